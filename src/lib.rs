@@ -1,17 +1,27 @@
 use std::{
     collections::VecDeque,
+    future::Future,
+    pin::Pin,
     sync::{Arc, Condvar, Mutex},
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
 };
 
 pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
     let inner = Inner {
         queue: VecDeque::default(),
         senders: 1,
+        receivers: 1,
+        capacity: None,
+        waiting_receivers: 0,
     };
 
     let shared = Shared {
         inner: Mutex::new(inner),
         available: Condvar::new(),
+        space: Condvar::new(),
+        wakers: Mutex::new(Vec::new()),
+        listeners: Mutex::new(Vec::new()),
     };
 
     let shared = Arc::new(shared);
@@ -21,7 +31,38 @@ pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
         },
         Receiver {
             shared: shared.clone(),
-            buffer: VecDeque::new(),
+        },
+    )
+}
+
+/// Like [`channel`], but bounds the queue to `cap` pending items. Once the
+/// queue holds `cap` items, [`SyncSender::send`] blocks until the receiver
+/// drains one. `cap == 0` gives a rendezvous channel: a send only completes
+/// once a receiver is actively waiting to take it.
+pub fn sync_channel<T>(cap: usize) -> (SyncSender<T>, Receiver<T>) {
+    let inner = Inner {
+        queue: VecDeque::default(),
+        senders: 1,
+        receivers: 1,
+        capacity: Some(cap),
+        waiting_receivers: 0,
+    };
+
+    let shared = Shared {
+        inner: Mutex::new(inner),
+        available: Condvar::new(),
+        space: Condvar::new(),
+        wakers: Mutex::new(Vec::new()),
+        listeners: Mutex::new(Vec::new()),
+    };
+
+    let shared = Arc::new(shared);
+    (
+        SyncSender {
+            shared: shared.clone(),
+        },
+        Receiver {
+            shared: shared.clone(),
         },
     )
 }
@@ -31,37 +72,277 @@ pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
 
 pub struct Receiver<T> {
     shared: Arc<Shared<T>>,
-    buffer: VecDeque<T>,
 }
 
 impl<T> Receiver<T> {
-    pub fn recv(&mut self) -> Option<T> {
-        if let Some(t) = self.buffer.pop_front() {
-            return Some(t);
+    pub fn recv(&mut self) -> Result<T, RecvError> {
+        let mut inner = self.shared.inner.lock().unwrap();
+        // Whether this call has registered itself in `waiting_receivers`.
+        // A rendezvous sender claims (decrements) that slot atomically with
+        // its push, so we must only ever undo our own registration here,
+        // not the claim a sender already made on our behalf.
+        let mut parked = false;
+        loop {
+            match inner.queue.pop_front() {
+                Some(t) => {
+                    drop(inner);
+                    // A slot just freed up, so a blocked bounded sender may
+                    // be able to make progress.
+                    self.shared.space.notify_one();
+                    return Ok(t);
+                }
+                // If no item has arrived yet, we pause the current thread
+                // until notified by the Condvar
+                None if inner.senders == 0 => {
+                    if parked {
+                        inner.waiting_receivers -= 1;
+                    }
+                    return Err(RecvError);
+                }
+                None => {
+                    if !parked {
+                        inner.waiting_receivers += 1;
+                        parked = true;
+                        // A rendezvous sender may be parked waiting for
+                        // exactly this, so let it know before we sleep.
+                        self.shared.space.notify_one();
+                    }
+                    inner = self.shared.available.wait(inner).unwrap();
+                }
+            }
         }
+    }
 
+    /// Like [`Receiver::recv`], but returns immediately instead of blocking
+    /// when no value is available yet.
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
         let mut inner = self.shared.inner.lock().unwrap();
+        match inner.queue.pop_front() {
+            Some(t) => {
+                drop(inner);
+                self.shared.space.notify_one();
+                Ok(t)
+            }
+            None if inner.senders == 0 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Like [`Receiver::recv`], but gives up after `dur` has elapsed instead
+    /// of blocking forever.
+    pub fn recv_timeout(&mut self, dur: Duration) -> Result<T, RecvTimeoutError> {
+        let deadline = Instant::now() + dur;
+        let mut inner = self.shared.inner.lock().unwrap();
+        // See the comment in `recv`: only undo our own registration, since a
+        // rendezvous sender may have already claimed it atomically with its
+        // push.
+        let mut parked = false;
         loop {
             match inner.queue.pop_front() {
                 Some(t) => {
-                    std::mem::swap(&mut self.buffer, &mut inner.queue);
-                    return Some(t);
+                    drop(inner);
+                    self.shared.space.notify_one();
+                    return Ok(t);
+                }
+                None if inner.senders == 0 => {
+                    if parked {
+                        inner.waiting_receivers -= 1;
+                    }
+                    return Err(RecvTimeoutError::Disconnected);
                 }
-                // If no item has arrived yet, we reassign the queue and
-                // pause the current thread until notified by the Condvar
-                None if inner.senders == 0 => return None,
                 None => {
-                    inner = self.shared.available.wait(inner).unwrap();
+                    let Some(remaining) = deadline.checked_duration_since(Instant::now()) else {
+                        if parked {
+                            inner.waiting_receivers -= 1;
+                        }
+                        return Err(RecvTimeoutError::Timeout);
+                    };
+                    if !parked {
+                        inner.waiting_receivers += 1;
+                        parked = true;
+                        // A rendezvous sender may be parked waiting for
+                        // exactly this, so let it know before we sleep.
+                        self.shared.space.notify_one();
+                    }
+                    let (new_inner, _timeout_result) =
+                        self.shared.available.wait_timeout(inner, remaining).unwrap();
+                    inner = new_inner;
+                    // A spurious wakeup may fire before the deadline with
+                    // nothing queued yet; loop back around to re-check the
+                    // remaining time rather than trusting `timed_out()`.
+                }
+            }
+        }
+    }
+
+    /// Returns a future that resolves to the next value, so the receiver can
+    /// be awaited from an async task instead of blocking the thread.
+    pub fn recv_async(&mut self) -> RecvFuture<'_, T> {
+        RecvFuture { receiver: self }
+    }
+
+    fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut inner = self.shared.inner.lock().unwrap();
+        match inner.queue.pop_front() {
+            Some(t) => {
+                drop(inner);
+                self.shared.space.notify_one();
+                Poll::Ready(Some(t))
+            }
+            None if inner.senders == 0 => Poll::Ready(None),
+            None => {
+                let mut wakers = self.shared.wakers.lock().unwrap();
+                if !wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                    wakers.push(cx.waker().clone());
                 }
+                Poll::Pending
             }
         }
     }
 }
 
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        let mut inner = self.shared.inner.lock().unwrap();
+        inner.receivers += 1;
+        drop(inner);
+
+        Receiver {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
 impl<T> Iterator for Receiver<T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
-        self.recv()
+        self.recv().ok()
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut inner = self.shared.inner.lock().unwrap();
+        inner.receivers -= 1;
+        drop(inner);
+        // Wake any sender blocked on capacity so it can observe that this
+        // was the last receiver and return a SendError instead of hanging.
+        self.shared.space.notify_all();
+    }
+}
+
+/// Mirrors `futures_core::Stream`'s shape so callers who already depend on
+/// the `futures`/`futures-core` ecosystem can adapt a [`Receiver`] with a
+/// one-line wrapper, without this crate taking on an external dependency
+/// itself.
+pub trait Stream {
+    type Item;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>;
+}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.get_mut().poll_recv(cx)
+    }
+}
+
+/// Future returned by [`Receiver::recv_async`].
+pub struct RecvFuture<'a, T> {
+    receiver: &'a mut Receiver<T>,
+}
+
+impl<'a, T> Future for RecvFuture<'a, T> {
+    type Output = Result<T, RecvError>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.get_mut().receiver.poll_recv(cx) {
+            Poll::Ready(Some(t)) => Poll::Ready(Ok(t)),
+            Poll::Ready(None) => Poll::Ready(Err(RecvError)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+////////////////// Select /////////////////////////////////
+//////////////////       /////////////////////////////////
+
+/// Blocks on several [`Receiver`]s at once and returns the first value
+/// produced by any of them, along with the index into `receivers` of the
+/// channel it came from.
+///
+/// Returns [`RecvError`] once every channel in `receivers` has disconnected
+/// with nothing left to deliver, instead of parking forever.
+///
+/// A spuriously-woken thread that finds every channel empty re-parks
+/// instead of busy-looping.
+pub fn select<T>(receivers: &mut [&mut Receiver<T>]) -> Result<(usize, T), RecvError> {
+    let signal = Arc::new(Signal::new());
+
+    for rx in receivers.iter() {
+        rx.shared.register_listener(Arc::clone(&signal));
+    }
+
+    let result = loop {
+        let mut ready = None;
+        let mut all_disconnected = true;
+        for (i, rx) in receivers.iter_mut().enumerate() {
+            match rx.try_recv() {
+                Ok(t) => {
+                    ready = Some(Ok((i, t)));
+                    break;
+                }
+                Err(TryRecvError::Empty) => all_disconnected = false,
+                Err(TryRecvError::Disconnected) => {}
+            }
+        }
+
+        if let Some(result) = ready {
+            break result;
+        }
+
+        if all_disconnected {
+            break Err(RecvError);
+        }
+
+        signal.wait();
+    };
+
+    for rx in receivers.iter() {
+        rx.shared.deregister_listener(&signal);
+    }
+
+    result
+}
+
+/// A one-shot-reusable wakeup token that several channels' [`Shared`]
+/// structs can fire on push, so [`select`] can park a single thread across
+/// all of them instead of polling each `Condvar` separately.
+struct Signal {
+    ready: Mutex<bool>,
+    condvar: Condvar,
+}
+
+impl Signal {
+    fn new() -> Self {
+        Signal {
+            ready: Mutex::new(false),
+            condvar: Condvar::new(),
+        }
+    }
+
+    fn notify(&self) {
+        let mut ready = self.ready.lock().unwrap();
+        *ready = true;
+        drop(ready);
+        self.condvar.notify_one();
+    }
+
+    fn wait(&self) {
+        let mut ready = self.ready.lock().unwrap();
+        while !*ready {
+            ready = self.condvar.wait(ready).unwrap();
+        }
+        *ready = false;
     }
 }
 
@@ -73,13 +354,25 @@ pub struct Sender<T> {
 }
 
 impl<T> Sender<T> {
-    pub fn send(&self, t: T) {
+    pub fn send(&self, t: T) -> Result<(), SendError<T>> {
         let mut inner = self.shared.inner.lock().unwrap();
+        if inner.receivers == 0 {
+            return Err(SendError(t));
+        }
         inner.queue.push_back(t);
+        // Claim a parked receiver's slot atomically with the push, so a
+        // concurrent sender checking `waiting_receivers` right after us sees
+        // this one as already served.
+        if inner.waiting_receivers > 0 {
+            inner.waiting_receivers -= 1;
+        }
         // Drop the lock so the other thread can immediately take the lock
         drop(inner);
         // Notify the the thread to wake up using the Condvar
         self.shared.available.notify_one();
+        self.shared.wake_task();
+        self.shared.notify_listeners();
+        Ok(())
     }
 }
 
@@ -102,7 +395,85 @@ impl<T> Drop for Sender<T> {
         let was_last = inner.senders == 0;
         drop(inner);
         if was_last {
-            self.shared.available.notify_one();
+            // Every receiver may be a clone parked in its own recv() call,
+            // so all of them need waking to observe the disconnect, not
+            // just whichever one notify_one happens to pick.
+            self.shared.available.notify_all();
+            self.shared.wake_task();
+            self.shared.notify_listeners();
+        }
+    }
+}
+
+////////////////// SyncSender /////////////////////////////////
+//////////////////            /////////////////////////////////
+
+pub struct SyncSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> SyncSender<T> {
+    pub fn send(&self, t: T) -> Result<(), SendError<T>> {
+        let mut inner = self.shared.inner.lock().unwrap();
+        if inner.receivers == 0 {
+            return Err(SendError(t));
+        }
+        let cap = inner.capacity.expect("SyncSender always has a capacity");
+        if cap == 0 {
+            // Rendezvous: don't hand off the value until a receiver is
+            // parked waiting for one.
+            while inner.receivers > 0 && inner.waiting_receivers == 0 {
+                inner = self.shared.space.wait(inner).unwrap();
+            }
+        } else {
+            while inner.receivers > 0 && inner.queue.len() == cap {
+                inner = self.shared.space.wait(inner).unwrap();
+            }
+        }
+        if inner.receivers == 0 {
+            return Err(SendError(t));
+        }
+        inner.queue.push_back(t);
+        // Claim a parked receiver's slot atomically with the push. For the
+        // rendezvous (`cap == 0`) case this is what stops a second sender,
+        // racing the same waiting receiver, from observing a stale nonzero
+        // count and also pushing: by the time it can acquire the lock, the
+        // claim above has already zeroed it out.
+        if inner.waiting_receivers > 0 {
+            inner.waiting_receivers -= 1;
+        }
+        drop(inner);
+        self.shared.available.notify_one();
+        self.shared.wake_task();
+        self.shared.notify_listeners();
+        Ok(())
+    }
+}
+
+impl<T> Clone for SyncSender<T> {
+    fn clone(&self) -> Self {
+        let mut inner = self.shared.inner.lock().unwrap();
+        inner.senders += 1;
+        drop(inner);
+
+        SyncSender {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for SyncSender<T> {
+    fn drop(&mut self) {
+        let mut inner = self.shared.inner.lock().unwrap();
+        inner.senders -= 1;
+        let was_last = inner.senders == 0;
+        drop(inner);
+        if was_last {
+            // See the comment in `Sender::drop`: every clone of `Receiver`
+            // may be independently parked in recv()/recv_timeout().
+            self.shared.available.notify_all();
+            self.shared.wake_task();
+            self.shared.notify_listeners();
         }
     }
 }
@@ -113,6 +484,9 @@ impl<T> Drop for Sender<T> {
 pub struct Inner<T> {
     queue: VecDeque<T>,
     senders: usize,
+    receivers: usize,
+    capacity: Option<usize>,
+    waiting_receivers: usize,
 }
 
 ////////////////// Shared /////////////////////////////////
@@ -121,6 +495,73 @@ pub struct Inner<T> {
 pub struct Shared<T> {
     inner: Mutex<Inner<T>>,
     available: Condvar,
+    space: Condvar,
+    wakers: Mutex<Vec<Waker>>,
+    listeners: Mutex<Vec<Arc<Signal>>>,
+}
+
+impl<T> Shared<T> {
+    /// Wakes every task currently polling this channel, if any. Mirrors
+    /// `available.notify_all()` for the async path.
+    ///
+    /// Cloned receivers mean more than one task can be parked here at once,
+    /// so every registered waker is drained and woken rather than just the
+    /// most recently registered one; each task re-polls and races for the
+    /// item like any other MPMC consumer.
+    fn wake_task(&self) {
+        for waker in self.wakers.lock().unwrap().drain(..) {
+            waker.wake();
+        }
+    }
+
+    fn register_listener(&self, signal: Arc<Signal>) {
+        self.listeners.lock().unwrap().push(signal);
+    }
+
+    fn deregister_listener(&self, signal: &Arc<Signal>) {
+        self.listeners
+            .lock()
+            .unwrap()
+            .retain(|s| !Arc::ptr_eq(s, signal));
+    }
+
+    /// Wakes every thread parked in [`select`], so it can rescan its
+    /// receivers now that this channel may have something for it.
+    fn notify_listeners(&self) {
+        for signal in self.listeners.lock().unwrap().iter() {
+            signal.notify();
+        }
+    }
+}
+
+////////////////// Errors /////////////////////////////////
+//////////////////        /////////////////////////////////
+
+/// The value could not be sent because every [`Receiver`] was dropped.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+/// The channel is empty and every [`Sender`]/[`SyncSender`] was dropped, so
+/// no further values can arrive.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RecvError;
+
+/// Error returned by [`Receiver::try_recv`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum TryRecvError {
+    /// No value is available yet, but senders are still alive.
+    Empty,
+    /// The channel is empty and every sender was dropped.
+    Disconnected,
+}
+
+/// Error returned by [`Receiver::recv_timeout`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum RecvTimeoutError {
+    /// No value arrived before the timeout elapsed.
+    Timeout,
+    /// The channel is empty and every sender was dropped.
+    Disconnected,
 }
 
 ////////////////// Tests /////////////////////////////////
@@ -128,20 +569,32 @@ pub struct Shared<T> {
 
 #[cfg(test)]
 mod tests {
-    use std::thread;
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        task::Wake,
+        thread,
+    };
 
     use super::*;
 
+    struct NoopWaker;
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
     #[test]
     fn send_string_across_threads() {
         let (tx, mut rx) = channel();
         let tx2 = tx.clone();
 
         thread::spawn(move || {
-            tx.send("YOOOOOOOOOOO");
+            tx.send("YOOOOOOOOOOO").unwrap();
         });
         thread::spawn(move || {
-            tx2.send("What Up");
+            tx2.send("What Up").unwrap();
         });
 
         assert_eq!(rx.next(), Some("YOOOOOOOOOOO"));
@@ -151,23 +604,244 @@ mod tests {
     #[test]
     fn ping_pong() {
         let (tx, mut rx) = channel();
-        tx.send(42);
+        tx.send(42).unwrap();
         let res = rx.recv();
-        assert_eq!(res, Some(42));
-        assert_ne!(res, Some(48));
+        assert_eq!(res, Ok(42));
+        assert_ne!(res, Ok(48));
     }
 
     #[test]
     fn closed() {
         let (tx, mut rx) = channel::<()>();
         drop(tx);
-        assert_eq!(rx.recv(), None);
+        assert_eq!(rx.recv(), Err(RecvError));
     }
 
     #[test]
     fn closed_rx() {
         let (tx, rx) = channel();
         drop(rx);
-        tx.send(42);
+        assert_eq!(tx.send(42), Err(SendError(42)));
+    }
+
+    #[test]
+    fn sync_channel_blocks_when_full() {
+        let (tx, mut rx) = sync_channel(1);
+        tx.send(1).unwrap();
+
+        let tx2 = tx.clone();
+        let handle = thread::spawn(move || {
+            tx2.send(2).unwrap();
+        });
+
+        assert_eq!(rx.recv(), Ok(1));
+        assert_eq!(rx.recv(), Ok(2));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn sync_channel_rendezvous() {
+        let (tx, mut rx) = sync_channel(0);
+
+        let handle = thread::spawn(move || {
+            tx.send(42).unwrap();
+        });
+
+        assert_eq!(rx.recv(), Ok(42));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn rendezvous_enforces_capacity_under_concurrent_senders() {
+        let (tx, mut rx) = sync_channel(0);
+        let tx2 = tx.clone();
+        let mut inspector = rx.clone();
+
+        let done = Arc::new(AtomicUsize::new(0));
+        let done1 = done.clone();
+        let done2 = done.clone();
+
+        // Park `rx` as the one rendezvous receiver up front.
+        let parked = thread::spawn(move || rx.recv().unwrap());
+        thread::sleep(Duration::from_millis(20));
+
+        // Two sends race for the single parked receiver. Only one may claim
+        // it; the other must keep blocking rather than buffering a second
+        // value past the zero-capacity bound.
+        let h1 = thread::spawn(move || {
+            tx.send(1).unwrap();
+            done1.fetch_add(1, Ordering::SeqCst);
+        });
+        let h2 = thread::spawn(move || {
+            tx2.send(2).unwrap();
+            done2.fetch_add(1, Ordering::SeqCst);
+        });
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(done.load(Ordering::SeqCst), 1);
+
+        let first = parked.join().unwrap();
+        let second = inspector.recv().unwrap();
+        h1.join().unwrap();
+        h2.join().unwrap();
+
+        let mut values = vec![first, second];
+        values.sort();
+        assert_eq!(values, vec![1, 2]);
+    }
+
+    #[test]
+    fn try_recv_reports_empty_and_disconnected() {
+        let (tx, mut rx) = channel();
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+
+        tx.send(1).unwrap();
+        assert_eq!(rx.try_recv(), Ok(1));
+
+        drop(tx);
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Disconnected));
+    }
+
+    #[test]
+    fn recv_timeout_times_out_while_the_sender_is_idle() {
+        let (tx, mut rx) = channel::<()>();
+        assert_eq!(
+            rx.recv_timeout(Duration::from_millis(10)),
+            Err(RecvTimeoutError::Timeout)
+        );
+        drop(tx);
+    }
+
+    #[test]
+    fn recv_async_parks_then_resolves() {
+        let (tx, mut rx) = channel();
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+
+        let mut fut = rx.recv_async();
+        assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Pending);
+
+        tx.send(7).unwrap();
+        // Shadow the pending future instead of calling `drop` on it
+        // explicitly: `RecvFuture` has no `Drop` impl, so an explicit drop
+        // trips clippy's `drop_non_drop` lint.
+        let mut fut = rx.recv_async();
+        assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Ready(Ok(7)));
+    }
+
+    #[test]
+    fn cloned_receivers_each_get_woken() {
+        let (tx, mut rx) = channel();
+        let mut rx2 = rx.clone();
+
+        let woken = Arc::new(AtomicUsize::new(0));
+
+        struct CountingWaker(Arc<AtomicUsize>);
+        impl Wake for CountingWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let waker1 = Waker::from(Arc::new(CountingWaker(woken.clone())));
+        let waker2 = Waker::from(Arc::new(CountingWaker(woken.clone())));
+        let mut cx1 = Context::from_waker(&waker1);
+        let mut cx2 = Context::from_waker(&waker2);
+
+        let mut fut1 = rx.recv_async();
+        let mut fut2 = rx2.recv_async();
+        assert_eq!(Pin::new(&mut fut1).poll(&mut cx1), Poll::Pending);
+        assert_eq!(Pin::new(&mut fut2).poll(&mut cx2), Poll::Pending);
+
+        tx.send(1).unwrap();
+
+        assert_eq!(woken.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn poll_next_ends_the_stream_once_closed() {
+        let (tx, mut rx) = channel::<()>();
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+
+        drop(tx);
+        assert_eq!(Pin::new(&mut rx).poll_next(&mut cx), Poll::Ready(None));
+    }
+
+    #[test]
+    fn disconnect_wakes_every_parked_clone() {
+        let (tx, rx) = channel::<()>();
+        let mut rx2 = rx.clone();
+        let mut rx3 = rx;
+
+        let h2 = thread::spawn(move || rx2.recv());
+        let h3 = thread::spawn(move || rx3.recv());
+
+        // Give both clones a chance to actually park in recv() before the
+        // only sender disconnects.
+        thread::sleep(Duration::from_millis(20));
+        drop(tx);
+
+        assert_eq!(h2.join().unwrap(), Err(RecvError));
+        assert_eq!(h3.join().unwrap(), Err(RecvError));
+    }
+
+    #[test]
+    fn cloned_receivers_compete_for_items() {
+        let (tx, mut rx) = channel();
+        let mut rx2 = rx.clone();
+
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        drop(tx);
+
+        let mut seen = vec![rx.recv().unwrap(), rx2.recv().unwrap()];
+        seen.sort();
+        assert_eq!(seen, vec![1, 2]);
+
+        assert_eq!(rx.recv(), Err(RecvError));
+        assert_eq!(rx2.recv(), Err(RecvError));
+    }
+
+    #[test]
+    fn select_returns_the_channel_that_fired() {
+        let (tx_a, mut rx_a) = channel();
+        let (tx_b, mut rx_b) = channel();
+
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            tx_b.send("from b").unwrap();
+            drop(tx_a);
+        });
+
+        let (index, value) = select(&mut [&mut rx_a, &mut rx_b]).unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(value, "from b");
+
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn select_errors_once_every_channel_disconnects() {
+        let (tx_a, mut rx_a) = channel::<()>();
+        let (tx_b, mut rx_b) = channel::<()>();
+        drop(tx_a);
+        drop(tx_b);
+
+        assert_eq!(select(&mut [&mut rx_a, &mut rx_b]), Err(RecvError));
+    }
+
+    #[test]
+    fn select_errors_when_a_live_channel_disconnects_while_parked() {
+        let (tx_a, mut rx_a) = channel::<()>();
+        let (tx_b, mut rx_b) = channel::<()>();
+
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            drop(tx_a);
+            drop(tx_b);
+        });
+
+        assert_eq!(select(&mut [&mut rx_a, &mut rx_b]), Err(RecvError));
+        handle.join().unwrap();
     }
 }